@@ -0,0 +1,76 @@
+//! The `cipher_mode` module provides the `CipherMode` enum, selecting which ChaCha-family AEAD
+//! cipher backs a [`Spha256`](crate::Spha256) instance.
+
+use serde::{Deserialize, Serialize};
+
+/// Selects which authenticated cipher [`Spha256`](crate::Spha256) uses to encrypt and decrypt
+/// data.
+///
+/// All variants are ChaCha-family AEAD ciphers; they differ in round count (a speed/security
+/// trade-off) and nonce length (collision resistance for randomly generated nonces). The variant
+/// in use is recorded as a one-byte tag ahead of the nonce in [`encrypt`](crate::Spha256::encrypt)
+/// output, so [`decrypt`](crate::Spha256::decrypt) can always select the matching cipher.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_hasher::CipherMode;
+///
+/// let mode = CipherMode::XChaCha20Poly1305;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CipherMode {
+    /// Standard 20-round ChaCha20-Poly1305 with a 12-byte nonce. The default.
+    ChaCha20Poly1305,
+    /// 20-round ChaCha20-Poly1305 with an extended 24-byte nonce, safe to generate randomly even
+    /// under very high encryption volume.
+    XChaCha20Poly1305,
+    /// Reduced-round (8-round) ChaCha-Poly1305 for constrained or latency-sensitive workloads.
+    ChaCha8Poly1305,
+    /// Reduced-round (12-round) ChaCha-Poly1305, a middle ground between speed and security.
+    ChaCha12Poly1305,
+}
+
+impl CipherMode {
+    /// The one-byte tag prepended to ciphertext so `decrypt` can identify the cipher used.
+    pub(crate) fn tag(self) -> u8 {
+        match self {
+            CipherMode::ChaCha20Poly1305 => 0,
+            CipherMode::XChaCha20Poly1305 => 1,
+            CipherMode::ChaCha8Poly1305 => 2,
+            CipherMode::ChaCha12Poly1305 => 3,
+        }
+    }
+
+    /// Recovers a `CipherMode` from a tag byte produced by [`tag`](Self::tag).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `tag` does not correspond to a known cipher mode.
+    pub(crate) fn from_tag(tag: u8) -> Result<Self, &'static str> {
+        match tag {
+            0 => Ok(CipherMode::ChaCha20Poly1305),
+            1 => Ok(CipherMode::XChaCha20Poly1305),
+            2 => Ok(CipherMode::ChaCha8Poly1305),
+            3 => Ok(CipherMode::ChaCha12Poly1305),
+            _ => Err("Unknown cipher mode tag"),
+        }
+    }
+
+    /// The nonce length required by this cipher, in bytes.
+    pub(crate) fn nonce_len(self) -> usize {
+        match self {
+            CipherMode::XChaCha20Poly1305 => 24,
+            CipherMode::ChaCha20Poly1305
+            | CipherMode::ChaCha8Poly1305
+            | CipherMode::ChaCha12Poly1305 => 12,
+        }
+    }
+}
+
+impl Default for CipherMode {
+    /// Defaults to standard ChaCha20-Poly1305, matching the cipher this crate has always used.
+    fn default() -> Self {
+        CipherMode::ChaCha20Poly1305
+    }
+}
@@ -0,0 +1,229 @@
+//! The `encoding` module adds hex and Base85 text encodings for [`Spha256`] ciphertext,
+//! mirroring how Bitcoin's hash types expose hex (de)serialization, so encrypted blobs can be
+//! safely embedded in text protocols, logs, and JSON without changing the binary format.
+
+use crate::Spha256;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error returned when parsing an encoded ciphertext fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodingError {
+    /// The encoded string's length doesn't correspond to a whole number of bytes.
+    InvalidLength,
+    /// The encoded string contains a character outside the expected alphabet.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::InvalidLength => write!(f, "encoded ciphertext has an invalid length"),
+            EncodingError::InvalidCharacter(c) => {
+                write!(f, "encoded ciphertext contains invalid character '{c}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodingError {}
+
+/// An error returned by [`Spha256::decrypt_from_hex`] and [`Spha256::decrypt_from_base85`].
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The encoded string could not be parsed into ciphertext bytes.
+    Encoding(EncodingError),
+    /// The ciphertext was parsed successfully but failed to decrypt.
+    Decryption(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Encoding(e) => write!(f, "{e}"),
+            DecodeError::Decryption(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// A nonce-prefixed ciphertext, as produced by [`Spha256::encrypt`], with hex and Base85 text
+/// (de)serialization.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_hasher::{EncryptedBlob, Point3D, RotationAxis, Spha256};
+/// use std::str::FromStr;
+///
+/// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+/// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+/// let hasher = Spha256::new(point, axis, 10, 0.1);
+///
+/// let blob = EncryptedBlob::from(hasher.encrypt(b"Secret Message"));
+/// let hex = blob.to_string();
+/// let round_tripped = EncryptedBlob::from_str(&hex).expect("valid hex");
+/// assert_eq!(blob, round_tripped);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedBlob(Vec<u8>);
+
+impl EncryptedBlob {
+    /// The raw nonce-prefixed ciphertext bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consumes the blob, returning the raw nonce-prefixed ciphertext bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Encodes this blob as a compact Base85 string.
+    pub fn to_base85(&self) -> String {
+        base85::encode(&self.0)
+    }
+
+    /// Parses a Base85 string produced by [`to_base85`](Self::to_base85) back into a blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::InvalidLength`] if `s` ends mid-group, or
+    /// [`EncodingError::InvalidCharacter`] if `s` contains a character outside the Base85
+    /// alphabet.
+    pub fn from_base85(s: &str) -> Result<Self, EncodingError> {
+        base85::decode(s).map(EncryptedBlob).map_err(|e| match e {
+            base85::Error::UnexpectedEof => EncodingError::InvalidLength,
+            base85::Error::InvalidCharacter(c) => EncodingError::InvalidCharacter(c as char),
+        })
+    }
+}
+
+impl From<Vec<u8>> for EncryptedBlob {
+    fn from(bytes: Vec<u8>) -> Self {
+        EncryptedBlob(bytes)
+    }
+}
+
+impl fmt::Display for EncryptedBlob {
+    /// Writes this blob as lowercase hex.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for EncryptedBlob {
+    type Err = EncodingError;
+
+    /// Parses a hex string produced by [`Display`](fmt::Display) back into a blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::InvalidLength`] if `s` has an odd number of characters, or
+    /// [`EncodingError::InvalidCharacter`] if `s` contains a non-hex-digit character.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() % 2 != 0 {
+            return Err(EncodingError::InvalidLength);
+        }
+
+        let mut bytes = Vec::with_capacity(chars.len() / 2);
+        for pair in chars.chunks(2) {
+            let hi = pair[0]
+                .to_digit(16)
+                .ok_or(EncodingError::InvalidCharacter(pair[0]))?;
+            let lo = pair[1]
+                .to_digit(16)
+                .ok_or(EncodingError::InvalidCharacter(pair[1]))?;
+            bytes.push(((hi << 4) | lo) as u8);
+        }
+
+        Ok(EncryptedBlob(bytes))
+    }
+}
+
+impl Spha256 {
+    /// Encrypts `data` and hex-encodes the result, for embedding in text configs, logs, or
+    /// JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes representing the data to encrypt.
+    ///
+    /// # Returns
+    ///
+    /// A lowercase hex string encoding the cipher mode tag, nonce, and ciphertext.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{Point3D, RotationAxis, Spha256};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::new(point, axis, 10, 0.1);
+    ///
+    /// let hex = hasher.encrypt_to_hex(b"Secret Message");
+    /// ```
+    pub fn encrypt_to_hex(&self, data: &[u8]) -> String {
+        EncryptedBlob::from(self.encrypt(data)).to_string()
+    }
+
+    /// Parses a hex string produced by [`encrypt_to_hex`](Self::encrypt_to_hex) and decrypts it.
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - A hex string as produced by [`encrypt_to_hex`](Self::encrypt_to_hex).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError::Encoding`] if `hex` is not valid hex, or
+    /// [`DecodeError::Decryption`] if the decoded ciphertext fails to decrypt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{Point3D, RotationAxis, Spha256};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::new(point, axis, 10, 0.1);
+    ///
+    /// let hex = hasher.encrypt_to_hex(b"Secret Message");
+    /// let decrypted = hasher.decrypt_from_hex(&hex).expect("Decryption failed");
+    /// assert_eq!(decrypted, b"Secret Message");
+    /// ```
+    pub fn decrypt_from_hex(&self, hex: &str) -> Result<Vec<u8>, DecodeError> {
+        let blob: EncryptedBlob = hex.parse().map_err(DecodeError::Encoding)?;
+        self.decrypt(blob.as_bytes())
+            .map_err(DecodeError::Decryption)
+    }
+
+    /// Encrypts `data` and encodes the result as a compact Base85 string.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes representing the data to encrypt.
+    ///
+    /// # Returns
+    ///
+    /// A Base85 string encoding the cipher mode tag, nonce, and ciphertext.
+    pub fn encrypt_to_base85(&self, data: &[u8]) -> String {
+        EncryptedBlob::from(self.encrypt(data)).to_base85()
+    }
+
+    /// Parses a Base85 string produced by [`encrypt_to_base85`](Self::encrypt_to_base85) and
+    /// decrypts it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError::Encoding`] if `base85` is not valid Base85, or
+    /// [`DecodeError::Decryption`] if the decoded ciphertext fails to decrypt.
+    pub fn decrypt_from_base85(&self, base85: &str) -> Result<Vec<u8>, DecodeError> {
+        let blob = EncryptedBlob::from_base85(base85).map_err(DecodeError::Encoding)?;
+        self.decrypt(blob.as_bytes())
+            .map_err(DecodeError::Decryption)
+    }
+}
@@ -0,0 +1,129 @@
+//! The `key_exchange` module provides X25519 key agreement, letting two parties who have never
+//! shared a secret derive the same [`Spha256`] instance.
+//!
+//! [`KeyPair`] wraps an X25519 keypair, and [`Spha256::from_key_exchange`] combines the
+//! Diffie–Hellman shared secret from a keypair and a peer's public key with HKDF-SHA256 to
+//! derive the encryption key. The spatial parameters (`point`, `rotation_axis`, `iterations`,
+//! `strength`) are hashed and used as the HKDF salt/info context, so they act as a domain
+//! separator binding the derived key to a particular conversation while the actual secrecy comes
+//! from the ephemeral ECDH exchange.
+
+use crate::{Point3D, RotationAxis, Spha256};
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+use x25519_dalek::StaticSecret;
+use zeroize::Zeroizing;
+
+/// An X25519 public key, as produced by [`KeyPair::public`] and consumed by
+/// [`Spha256::from_key_exchange`].
+pub use x25519_dalek::PublicKey;
+
+/// An X25519 keypair used in [`Spha256::from_key_exchange`] to derive a shared key with a peer.
+///
+/// # Examples
+///
+/// ```
+/// use spatial_hasher::KeyPair;
+///
+/// let keypair = KeyPair::generate();
+/// let public = keypair.public();
+/// ```
+///
+/// The secret scalar is kept in a [`Zeroizing`] buffer rather than a bare `StaticSecret`, so it
+/// is wiped from memory as soon as this `KeyPair` is dropped.
+#[derive(Clone)]
+pub struct KeyPair {
+    secret: Zeroizing<[u8; 32]>,
+    public: PublicKey,
+}
+
+impl KeyPair {
+    /// Generates a new random X25519 keypair.
+    ///
+    /// # Returns
+    ///
+    /// A new `KeyPair` with a securely-generated secret and its corresponding public key.
+    pub fn generate() -> Self {
+        let secret = StaticSecret::random_from_rng(OsRng);
+        let public = PublicKey::from(&secret);
+        KeyPair {
+            secret: Zeroizing::new(secret.to_bytes()),
+            public,
+        }
+    }
+
+    /// The public half of this keypair, safe to share with the other party.
+    ///
+    /// # Returns
+    ///
+    /// The `PublicKey` corresponding to this keypair's secret.
+    pub fn public(&self) -> PublicKey {
+        self.public
+    }
+}
+
+impl Spha256 {
+    /// Derives a shared `Spha256` instance from an X25519 key exchange.
+    ///
+    /// Performs a Diffie–Hellman exchange between `my_secret` and `their_public`, then runs
+    /// HKDF-SHA256 with the DH shared secret as input key material and the SHA-256 hash of the
+    /// spatial parameters as the salt/info context, producing the 32-byte key used by
+    /// [`encrypt`](Spha256::encrypt)/[`decrypt`](Spha256::decrypt). The spatial parameters no
+    /// longer need to be a shared secret between the two parties — they only need to agree on
+    /// them as a domain separator, since the key's secrecy comes from the ECDH exchange.
+    ///
+    /// # Arguments
+    ///
+    /// * `my_secret` - This party's `KeyPair`.
+    /// * `their_public` - The other party's public key.
+    /// * `point` - A `Point3D` used as part of the key-derivation context.
+    /// * `rotation_axis` - A `RotationAxis` used as part of the key-derivation context.
+    /// * `iterations` - The iteration count used as part of the key-derivation context.
+    /// * `strength` - The strength value used as part of the key-derivation context.
+    ///
+    /// # Returns
+    ///
+    /// A new `Spha256` instance whose encryption key is the HKDF output rather than the
+    /// rotation-walk key that [`Spha256::new`] would derive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{KeyPair, Point3D, RotationAxis, Spha256};
+    ///
+    /// let alice = KeyPair::generate();
+    /// let bob = KeyPair::generate();
+    ///
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    ///
+    /// let alice_hasher = Spha256::from_key_exchange(&alice, &bob.public(), point, axis, 10, 0.1);
+    /// let bob_hasher = Spha256::from_key_exchange(&bob, &alice.public(), point, axis, 10, 0.1);
+    ///
+    /// let encrypted = alice_hasher.encrypt(b"Secret Message");
+    /// let decrypted = bob_hasher.decrypt(&encrypted).expect("Decryption failed");
+    /// assert_eq!(decrypted, b"Secret Message");
+    /// ```
+    pub fn from_key_exchange(
+        my_secret: &KeyPair,
+        their_public: &PublicKey,
+        point: Point3D,
+        rotation_axis: RotationAxis,
+        iterations: u32,
+        strength: f64,
+    ) -> Self {
+        let shared_secret =
+            StaticSecret::from(*my_secret.secret).diffie_hellman(their_public);
+
+        let hasher = Spha256::new(point, rotation_axis, iterations, strength);
+        let context = Sha256::digest(hasher.spatial_aad());
+
+        let hkdf = Hkdf::<Sha256>::new(Some(&context), shared_secret.as_bytes());
+        let mut derived_key = Zeroizing::new([0u8; 32]);
+        hkdf.expand(&context, &mut *derived_key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+
+        hasher.with_derived_key(*derived_key)
+    }
+}
@@ -12,6 +12,12 @@
 //! - **Secure Authenticated Encryption**: Uses the ChaCha20-Poly1305 algorithm for strong encryption and integrity protection.
 //! - **Deterministic Key Derivation**: Generates a consistent key from spatial parameters, allowing for reproducible encryption and decryption.
 //! - **Customizable Parameters**: Adjust the starting point, rotation axis, iterations, and strength to modify the encryption.
+//! - **Selectable Cipher Modes**: Choose between standard ChaCha20-Poly1305, XChaCha20-Poly1305 for safe random nonces at high volume, or reduced-round variants for lightweight workloads.
+//! - **Associated Data Binding**: The spatial parameters are authenticated as AAD by default, and `encrypt_with_aad`/`decrypt_with_aad` let callers bind their own associated data too.
+//! - **Streaming Encryption**: `encrypt_stream`/`decrypt_stream` process large files in bounded-memory chunks using the `aead::stream` STREAM construction.
+//! - **Public-Key Mode**: `Spha256::from_key_exchange` derives a shared instance from an X25519 Diffie–Hellman exchange, so two parties can communicate without a pre-shared secret.
+//! - **Text-Safe Encoding**: `encrypt_to_hex`/`decrypt_from_hex` and `encrypt_to_base85`/`decrypt_from_base85` make ciphertext embeddable in text configs, logs, and JSON.
+//! - **Key Hygiene**: Derived keys are wrapped in `Zeroizing` and wiped on drop, and `Spha256`'s `Debug` impl never prints its secret parameters.
 //! - **Simple API**: Easy to integrate into other Rust projects.
 //! - **Serialization Support**: Structures can be serialized and deserialized using `serde`.
 //! - **Unit Tests Included**: Verify functionality with built-in tests.
@@ -21,6 +27,9 @@
 //! - **Point3D**: Represents a point in 3D space.
 //! - **RotationAxis**: Represents a rotation axis in 3D space.
 //! - **Spha256**: The core struct that provides encryption and decryption methods.
+//! - **CipherMode**: Selects which ChaCha-family AEAD cipher `Spha256` encrypts with.
+//! - **KeyPair**: An X25519 keypair used to derive a shared `Spha256` instance without a pre-shared secret.
+//! - **EncryptedBlob**: A nonce-prefixed ciphertext with hex and Base85 text (de)serialization.
 //!
 //! ## Architecture
 //!
@@ -29,10 +38,15 @@
 //!
 //! ### **Key Derivation**
 //!
-//! The key is derived by hashing the spatial parameters using SHA-256:
+//! The key is derived by walking `Point3D` along a rotation trajectory and hashing the result
+//! with SHA-256:
 //!
-//! - Coordinates of the `Point3D` and `RotationAxis`.
-//! - The `iterations` and `strength` parameters.
+//! - `RotationAxis` is normalized to a unit vector (falling back to the canonical Z axis if it is
+//!   the zero vector).
+//! - The point is rotated around that axis by `strength` radians, `iterations` times, using
+//!   Rodrigues' rotation formula.
+//! - The coordinates produced at each step are fed into a running SHA-256 state, so `iterations`
+//!   performs real key-stretching work.
 //!
 //! ### **Encryption Process**
 //!
@@ -84,17 +98,26 @@
 //! - [`Point3D`]: Represents a point in 3D space.
 //! - [`RotationAxis`]: Represents a rotation axis in 3D space.
 //! - [`Spha256`]: Provides methods for encryption and decryption.
+//! - [`CipherMode`]: Selects the ChaCha-family AEAD cipher used by a `Spha256` instance.
+//! - [`KeyPair`]: An X25519 keypair for [`Spha256::from_key_exchange`].
+//! - [`EncryptedBlob`]: A nonce-prefixed ciphertext with hex and Base85 text (de)serialization.
 //!
 //! ## Security Considerations
 //!
 //! The security of the encryption relies on the secrecy of the parameters used to derive the key. Ensure that the `Point3D`, `RotationAxis`, `iterations`, and `strength` parameters are kept confidential.
 //!
+//! Derived keys are wrapped in `zeroize::Zeroizing` and wiped as soon as they go out of scope, and `Spha256` itself zeroizes its `point`, `rotation_axis`, `iterations`, `strength`, and `derived_key` fields on drop (`cipher_mode` isn't secret, so it's excluded). `Spha256`'s `Debug` impl always prints `Spha256 { .. }`, so accidentally logging an instance cannot leak its parameters or key material.
+//!
 //! ## Dependencies
 //!
 //! - `chacha20poly1305` for encryption
 //! - `serde` for serialization
 //! - `sha2` for SHA-256 hashing
 //! - `rand` for random number generation
+//! - `hkdf` for key derivation in public-key mode
+//! - `x25519-dalek` for X25519 key agreement
+//! - `base85` for compact text encoding of ciphertext
+//! - `zeroize` for wiping derived key material from memory
 //!
 //! ## License
 //!
@@ -107,10 +130,17 @@
 //! [RotationAxis]: crate::spatial_hasher::RotationAxis
 //! [Spha256]: crate::spatial_hasher::Spha256
 
+pub mod cipher_mode;
+pub mod encoding;
+pub mod key_exchange;
 pub mod point3d;
 pub mod rotation_axis;
 pub mod spatial_hasher;
+mod streaming;
 
+pub use cipher_mode::CipherMode;
+pub use encoding::{DecodeError, EncodingError, EncryptedBlob};
+pub use key_exchange::{KeyPair, PublicKey};
 pub use point3d::Point3D;
 pub use rotation_axis::RotationAxis;
 pub use spatial_hasher::Spha256;
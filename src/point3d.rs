@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
+use zeroize::Zeroize;
 
 /// Represents a point in 3D space with floating-point coordinates.
 ///
@@ -11,7 +12,7 @@ use std::hash::{Hash, Hasher};
 /// use spatial_hasher::Point3D;
 /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
 /// ```
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Zeroize)]
 pub struct Point3D {
     pub x: f64,
     pub y: f64,
@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
+use zeroize::Zeroize;
 /// Represents an axis of rotation in 3D space.
 ///
 /// # Fields
@@ -17,7 +18,7 @@ use std::hash::{Hash, Hasher};
 ///
 /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
 /// ```
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Zeroize)]
 pub struct RotationAxis {
     /// The x-component of the rotation axis.
     pub x: f64,
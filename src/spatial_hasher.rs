@@ -1,11 +1,13 @@
-use crate::{Point3D, RotationAxis};
+use crate::{CipherMode, Point3D, RotationAxis};
 use chacha20poly1305::{
-    aead::{Aead, KeyInit, OsRng},
-    ChaCha20Poly1305, Nonce,
+    aead::{Aead, KeyInit, OsRng, Payload},
+    ChaCha12Poly1305, ChaCha20Poly1305, ChaCha8Poly1305, Nonce, XChaCha20Poly1305, XNonce,
 };
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 /// A hasher that uses a 3D point and a rotation axis to encrypt and decrypt data.
 ///
@@ -19,7 +21,12 @@ use sha2::{Digest, Sha256};
 /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
 /// let hasher = Spha256::new(point, axis, 10, 0.1);
 /// ```
-#[derive(Clone, Debug, Serialize, Deserialize)]
+///
+/// `Spha256`'s [`Debug`] impl does not print its spatial parameters or derived key, so instances
+/// can't be accidentally leaked through logs: it always prints as `Spha256 { .. }`. The spatial
+/// parameters and key material are also wiped from memory when a `Spha256` is dropped, since the
+/// security of the encryption depends on their secrecy.
+#[derive(Clone, Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
 pub struct Spha256 {
     /// The starting point in 3D space.
     point: Point3D,
@@ -29,6 +36,22 @@ pub struct Spha256 {
     iterations: u32,
     /// The strength of the transformation.
     strength: f64,
+    /// The AEAD cipher used for encryption and decryption. Not secret, so it's left out of the
+    /// zeroize-on-drop wipe.
+    #[zeroize(skip)]
+    cipher_mode: CipherMode,
+    /// A key derived by an out-of-band mechanism (e.g. [`from_key_exchange`](Self::from_key_exchange))
+    /// that takes precedence over [`generate_key`](Self::generate_key) when present.
+    #[serde(skip)]
+    derived_key: Option<[u8; 32]>,
+}
+
+impl fmt::Debug for Spha256 {
+    /// Prints `Spha256 { .. }` without exposing the spatial parameters or derived key, so this
+    /// instance can't be accidentally disclosed through debug logging.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spha256").finish_non_exhaustive()
+    }
 }
 
 impl Spha256 {
@@ -58,34 +81,134 @@ impl Spha256 {
         rotation_axis: RotationAxis,
         iterations: u32,
         strength: f64,
+    ) -> Self {
+        Self::with_cipher_mode(point, rotation_axis, iterations, strength, CipherMode::default())
+    }
+
+    /// Creates a new `Spha256` instance with the specified parameters and AEAD cipher.
+    ///
+    /// This is identical to [`new`](Self::new), but lets the caller pick a [`CipherMode`] other
+    /// than the default `ChaCha20Poly1305` — for example `XChaCha20Poly1305` for safe random
+    /// nonces under high encryption volume, or a reduced-round variant for constrained workloads.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - A `Point3D` specifying the starting point in 3D space.
+    /// * `rotation_axis` - A `RotationAxis` specifying the axis of rotation.
+    /// * `iterations` - The number of iterations to perform in the hashing process.
+    /// * `strength` - A floating-point value representing the strength of the transformation.
+    /// * `cipher_mode` - The AEAD cipher to encrypt and decrypt with.
+    ///
+    /// # Returns
+    ///
+    /// A new `Spha256` instance configured with the provided parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{CipherMode, Point3D, RotationAxis, Spha256};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::with_cipher_mode(point, axis, 10, 0.1, CipherMode::XChaCha20Poly1305);
+    /// ```
+    pub fn with_cipher_mode(
+        point: Point3D,
+        rotation_axis: RotationAxis,
+        iterations: u32,
+        strength: f64,
+        cipher_mode: CipherMode,
     ) -> Self {
         Spha256 {
             point,
             rotation_axis,
             iterations,
             strength,
+            cipher_mode,
+            derived_key: None,
         }
     }
 
-    /// Generates a 256-bit key by hashing the hasher's parameters.
+    /// Returns this instance with its encryption key overridden to `derived_key`.
+    ///
+    /// Used by [`from_key_exchange`](Self::from_key_exchange) to install an HKDF-derived key in
+    /// place of the rotation-walk key that [`generate_key`](Self::generate_key) would otherwise
+    /// produce.
+    pub(crate) fn with_derived_key(mut self, derived_key: [u8; 32]) -> Self {
+        self.derived_key = Some(derived_key);
+        self
+    }
+
+    /// The AEAD cipher this instance encrypts and decrypts with.
+    pub(crate) fn cipher_mode(&self) -> CipherMode {
+        self.cipher_mode
+    }
+
+    /// The encryption key for this instance: the HKDF-derived key installed by
+    /// [`from_key_exchange`](Self::from_key_exchange) if present, otherwise the
+    /// [`generate_key`](Self::generate_key) rotation-walk key.
+    ///
+    /// The key is wrapped in [`Zeroizing`] so it is wiped from memory as soon as the caller drops
+    /// it, rather than lingering on the stack after use.
+    pub(crate) fn key(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.derived_key.unwrap_or_else(|| self.generate_key()))
+    }
+
+    /// Generates a 256-bit key by walking `point` along a rotation trajectory and hashing the
+    /// result.
+    ///
+    /// Starting from `point`, this function applies Rodrigues' rotation formula `iterations`
+    /// times, rotating the current vector by `strength` radians around the unit vector derived
+    /// from `rotation_axis`. The starting point, normalized axis, and the sine and cosine of
+    /// `strength` are fed into a running SHA-256 state up front, and after each rotation step the bit representation of the
+    /// new coordinates is fed in as well, so `iterations` performs genuine key-stretching work,
+    /// `strength`/`rotation_axis` materially change the derived key, and `iterations == 0` still
+    /// produces a key tied to this instance's parameters rather than a constant digest. The walk
+    /// is fully deterministic, so the same parameters always reproduce the same key.
     ///
-    /// This function uses the SHA-256 hash function to create a key based on the bit representations of the `point`, `rotation_axis`, `iterations`, and `strength` fields. The key is used in the [`encrypt`](#method.encrypt) and [`decrypt`](#method.decrypt) methods with the ChaCha20-Poly1305 cipher.
+    /// If `rotation_axis` is the zero vector (and therefore has no well-defined direction), the
+    /// canonical Z axis is used in its place.
     ///
     /// # Returns
     ///
     /// A 32-byte array representing the encryption key.
-    fn generate_key(&self) -> [u8; 32] {
+    pub(crate) fn generate_key(&self) -> [u8; 32] {
+        let axis_len = (self.rotation_axis.x * self.rotation_axis.x
+            + self.rotation_axis.y * self.rotation_axis.y
+            + self.rotation_axis.z * self.rotation_axis.z)
+            .sqrt();
+        let k = if axis_len > f64::EPSILON {
+            (
+                self.rotation_axis.x / axis_len,
+                self.rotation_axis.y / axis_len,
+                self.rotation_axis.z / axis_len,
+            )
+        } else {
+            (0.0, 0.0, 1.0)
+        };
+
+        let (sin_theta, cos_theta) = self.strength.sin_cos();
+
         let mut hasher = Sha256::new();
-        hasher.update(&self.point.x.to_bits().to_ne_bytes());
-        hasher.update(&self.point.y.to_bits().to_ne_bytes());
-        hasher.update(&self.point.z.to_bits().to_ne_bytes());
+        let mut v = (self.point.x, self.point.y, self.point.z);
 
-        hasher.update(&self.rotation_axis.x.to_bits().to_ne_bytes());
-        hasher.update(&self.rotation_axis.y.to_bits().to_ne_bytes());
-        hasher.update(&self.rotation_axis.z.to_bits().to_ne_bytes());
+        // Hash the starting point, normalized axis, and sin/cos of strength before any rotation steps, so
+        // `iterations == 0` still produces a key tied to this instance's parameters instead of
+        // the constant digest of an empty input.
+        hasher.update(&v.0.to_bits().to_ne_bytes());
+        hasher.update(&v.1.to_bits().to_ne_bytes());
+        hasher.update(&v.2.to_bits().to_ne_bytes());
+        hasher.update(&k.0.to_bits().to_ne_bytes());
+        hasher.update(&k.1.to_bits().to_ne_bytes());
+        hasher.update(&k.2.to_bits().to_ne_bytes());
+        hasher.update(&sin_theta.to_bits().to_ne_bytes());
+        hasher.update(&cos_theta.to_bits().to_ne_bytes());
 
-        hasher.update(&self.iterations.to_ne_bytes());
-        hasher.update(&self.strength.to_bits().to_ne_bytes());
+        for _ in 0..self.iterations {
+            v = Self::rotate(v, k, sin_theta, cos_theta);
+            hasher.update(&v.0.to_bits().to_ne_bytes());
+            hasher.update(&v.1.to_bits().to_ne_bytes());
+            hasher.update(&v.2.to_bits().to_ne_bytes());
+        }
 
         let result = hasher.finalize();
         let mut key = [0u8; 32];
@@ -93,9 +216,63 @@ impl Spha256 {
         key
     }
 
-    /// Encrypts the provided data using the ChaCha20-Poly1305 authenticated encryption algorithm.
+    /// Rotates `v` around the unit vector `k` by the angle whose sine/cosine are given, using
+    /// Rodrigues' rotation formula:
     ///
-    /// This method encrypts the input data using the ChaCha20-Poly1305 cipher, with a key derived from the hasher's parameters via the [`generate_key`](#method.generate_key) method. A random nonce is generated for each encryption operation to ensure uniqueness and security. The nonce is prepended to the ciphertext for use during decryption.
+    /// `v_rot = v·cos θ + (k × v)·sin θ + k·(k·v)·(1 − cos θ)`
+    fn rotate(
+        v: (f64, f64, f64),
+        k: (f64, f64, f64),
+        sin_theta: f64,
+        cos_theta: f64,
+    ) -> (f64, f64, f64) {
+        let k_dot_v = k.0 * v.0 + k.1 * v.1 + k.2 * v.2;
+        let k_cross_v = (
+            k.1 * v.2 - k.2 * v.1,
+            k.2 * v.0 - k.0 * v.2,
+            k.0 * v.1 - k.1 * v.0,
+        );
+
+        (
+            v.0 * cos_theta + k_cross_v.0 * sin_theta + k.0 * k_dot_v * (1.0 - cos_theta),
+            v.1 * cos_theta + k_cross_v.1 * sin_theta + k.1 * k_dot_v * (1.0 - cos_theta),
+            v.2 * cos_theta + k_cross_v.2 * sin_theta + k.2 * k_dot_v * (1.0 - cos_theta),
+        )
+    }
+
+    /// Serializes the spatial parameters (point, rotation axis, iterations, and strength — but
+    /// not the cipher mode) into a canonical byte string.
+    ///
+    /// This is used as the default Additional Authenticated Data (AAD) for
+    /// [`encrypt`](#method.encrypt)/[`decrypt`](#method.decrypt), so a ciphertext
+    /// cryptographically binds to the exact parameter set it was produced with, not just the key
+    /// derived from them.
+    pub(crate) fn spatial_aad(&self) -> Vec<u8> {
+        let mut aad = Vec::with_capacity(8 * 7 + 4);
+        aad.extend_from_slice(&self.point.x.to_bits().to_ne_bytes());
+        aad.extend_from_slice(&self.point.y.to_bits().to_ne_bytes());
+        aad.extend_from_slice(&self.point.z.to_bits().to_ne_bytes());
+        aad.extend_from_slice(&self.rotation_axis.x.to_bits().to_ne_bytes());
+        aad.extend_from_slice(&self.rotation_axis.y.to_bits().to_ne_bytes());
+        aad.extend_from_slice(&self.rotation_axis.z.to_bits().to_ne_bytes());
+        aad.extend_from_slice(&self.iterations.to_ne_bytes());
+        aad.extend_from_slice(&self.strength.to_bits().to_ne_bytes());
+        aad
+    }
+
+    /// Encrypts the provided data using the hasher's configured AEAD cipher.
+    ///
+    /// This method encrypts the input data using the [`CipherMode`] selected for this instance
+    /// (`ChaCha20Poly1305` by default), with a key derived from the hasher's parameters via the
+    /// [`generate_key`](#method.generate_key) method (or the HKDF key installed by
+    /// [`from_key_exchange`](#method.from_key_exchange), when present). The spatial parameters (`point`,
+    /// `rotation_axis`, `iterations`, `strength`) are bound into the authentication tag as
+    /// Additional Authenticated Data via [`encrypt_with_aad`](#method.encrypt_with_aad), so a
+    /// ciphertext produced with one set of parameters fails to authenticate against another. A
+    /// random nonce of the cipher's required length is generated for each encryption operation
+    /// to ensure uniqueness and security. A one-byte cipher mode tag followed by the nonce is
+    /// prepended to the ciphertext, so [`decrypt`](#method.decrypt) can select the same cipher
+    /// without needing to be told which one was used.
     ///
     /// # Arguments
     ///
@@ -103,7 +280,7 @@ impl Spha256 {
     ///
     /// # Returns
     ///
-    /// A `Vec<u8>` containing the encrypted data, with the nonce prepended.
+    /// A `Vec<u8>` containing the encrypted data, with the cipher mode tag and nonce prepended.
     ///
     /// # Examples
     ///
@@ -117,33 +294,84 @@ impl Spha256 {
     /// let encrypted = hasher.encrypt(data);
     /// ```
     pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.encrypt_with_aad(data, &self.spatial_aad())
+    }
+
+    /// Encrypts `data`, authenticating it together with `aad` as Additional Authenticated Data.
+    ///
+    /// `aad` is not encrypted and is not included in the returned bytes — the caller must supply
+    /// the same `aad` to [`decrypt_with_aad`](#method.decrypt_with_aad) for authentication to
+    /// succeed. [`encrypt`](#method.encrypt) is equivalent to calling this method with the
+    /// hasher's own spatial parameters as `aad`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of bytes representing the data to encrypt.
+    /// * `aad` - Additional data to authenticate but not encrypt.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<u8>` containing the encrypted data, with the cipher mode tag and nonce prepended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{Spha256, Point3D, RotationAxis};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::new(point, axis, 10, 0.1);
+    ///
+    /// let encrypted = hasher.encrypt_with_aad(b"Secret Message", b"message-id-42");
+    /// ```
+    pub fn encrypt_with_aad(&self, data: &[u8], aad: &[u8]) -> Vec<u8> {
         // Derive key from parameters
-        let key = self.generate_key();
-        let cipher = ChaCha20Poly1305::new(&key.into());
+        let key = self.key();
 
-        // Generate a random nonce (12 bytes for ChaCha20-Poly1305)
-        let mut nonce_bytes = [0u8; 12];
+        // Generate a random nonce of the length required by the configured cipher
+        let mut nonce_bytes = vec![0u8; self.cipher_mode.nonce_len()];
         OsRng.fill_bytes(&mut nonce_bytes);
-        let nonce = Nonce::from_slice(&nonce_bytes);
 
-        // Encrypt the data
-        let ciphertext = cipher.encrypt(nonce, data).expect("Encryption failed");
+        let payload = Payload { msg: data, aad };
+
+        let ciphertext = match self.cipher_mode {
+            CipherMode::ChaCha20Poly1305 => ChaCha20Poly1305::new(&(*key).into())
+                .encrypt(Nonce::from_slice(&nonce_bytes), payload)
+                .expect("Encryption failed"),
+            CipherMode::XChaCha20Poly1305 => XChaCha20Poly1305::new(&(*key).into())
+                .encrypt(XNonce::from_slice(&nonce_bytes), payload)
+                .expect("Encryption failed"),
+            CipherMode::ChaCha8Poly1305 => ChaCha8Poly1305::new(&(*key).into())
+                .encrypt(Nonce::from_slice(&nonce_bytes), payload)
+                .expect("Encryption failed"),
+            CipherMode::ChaCha12Poly1305 => ChaCha12Poly1305::new(&(*key).into())
+                .encrypt(Nonce::from_slice(&nonce_bytes), payload)
+                .expect("Encryption failed"),
+        };
 
-        // Prepend nonce to ciphertext
-        let mut encrypted = Vec::with_capacity(nonce_bytes.len() + ciphertext.len());
+        // Prepend the cipher mode tag and nonce to the ciphertext
+        let mut encrypted = Vec::with_capacity(1 + nonce_bytes.len() + ciphertext.len());
+        encrypted.push(self.cipher_mode.tag());
         encrypted.extend_from_slice(&nonce_bytes);
         encrypted.extend_from_slice(&ciphertext);
 
         encrypted
     }
 
-    /// Decrypts the provided data using the ChaCha20-Poly1305 authenticated decryption algorithm.
+    /// Decrypts the provided data using the cipher recorded in its mode tag.
     ///
-    /// This method decrypts the input data using the ChaCha20-Poly1305 cipher, with a key derived from the hasher's parameters via the [`generate_key`](#method.generate_key) method. The nonce used during encryption is expected to be prepended to the encrypted data and is extracted during decryption.
+    /// This method reads the one-byte [`CipherMode`] tag prepended by [`encrypt`](#method.encrypt),
+    /// extracts the nonce that follows it, and decrypts the remaining ciphertext with that
+    /// cipher, using a key derived from the hasher's parameters via the
+    /// [`generate_key`](#method.generate_key) method (or the HKDF key installed by
+    /// [`from_key_exchange`](#method.from_key_exchange), when present). The hasher's own spatial parameters are
+    /// required to match as Additional Authenticated Data via
+    /// [`decrypt_with_aad`](#method.decrypt_with_aad), so decryption fails if the ciphertext was
+    /// produced with a different `point`, `rotation_axis`, `iterations`, or `strength`.
     ///
     /// # Arguments
     ///
-    /// * `encrypted` - A slice of bytes representing the encrypted data, with the nonce prepended.
+    /// * `encrypted` - A slice of bytes representing the encrypted data, with the cipher mode tag
+    ///   and nonce prepended.
     ///
     /// # Returns
     ///
@@ -151,7 +379,9 @@ impl Spha256 {
     ///
     /// # Errors
     ///
-    /// Returns an error if the decryption fails, such as when the ciphertext has been tampered with or the parameters do not match those used during encryption.
+    /// Returns an error if the cipher mode tag is unrecognized, the data is too short to contain
+    /// a nonce, or decryption fails, such as when the ciphertext has been tampered with or the
+    /// parameters do not match those used during encryption.
     ///
     /// # Examples
     ///
@@ -166,22 +396,195 @@ impl Spha256 {
     /// assert_eq!(decrypted, b"Secret Message");
     /// ```
     pub fn decrypt(&self, encrypted: &[u8]) -> Result<Vec<u8>, &'static str> {
-        if encrypted.len() < 12 {
+        self.decrypt_with_aad(encrypted, &self.spatial_aad())
+    }
+
+    /// Decrypts `encrypted`, requiring it to authenticate against `aad` as Additional
+    /// Authenticated Data.
+    ///
+    /// `aad` must match exactly what was passed to
+    /// [`encrypt_with_aad`](#method.encrypt_with_aad) when the ciphertext was produced;
+    /// [`decrypt`](#method.decrypt) is equivalent to calling this method with the hasher's own
+    /// spatial parameters as `aad`.
+    ///
+    /// # Arguments
+    ///
+    /// * `encrypted` - A slice of bytes representing the encrypted data, with the cipher mode tag
+    ///   and nonce prepended.
+    /// * `aad` - The associated data that was authenticated at encryption time.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<Vec<u8>, &'static str>` containing the decrypted data on success, or an error message on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cipher mode tag is unrecognized, the data is too short to contain
+    /// a nonce, or decryption fails, such as when the ciphertext or `aad` do not match what was
+    /// supplied during encryption.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{Spha256, Point3D, RotationAxis};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::new(point, axis, 10, 0.1);
+    ///
+    /// let encrypted = hasher.encrypt_with_aad(b"Secret Message", b"message-id-42");
+    /// let decrypted = hasher
+    ///     .decrypt_with_aad(&encrypted, b"message-id-42")
+    ///     .expect("Decryption failed");
+    /// assert_eq!(decrypted, b"Secret Message");
+    /// ```
+    pub fn decrypt_with_aad(&self, encrypted: &[u8], aad: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let (tag, rest) = encrypted
+            .split_first()
+            .ok_or("Ciphertext too short to contain a cipher mode tag")?;
+        let mode = CipherMode::from_tag(*tag)?;
+
+        let nonce_len = mode.nonce_len();
+        if rest.len() < nonce_len {
             return Err("Ciphertext too short to contain nonce");
         }
-
-        let (nonce_bytes, ciphertext) = encrypted.split_at(12);
-        let nonce = Nonce::from_slice(nonce_bytes);
+        let (nonce_bytes, ciphertext) = rest.split_at(nonce_len);
 
         // Derive key from parameters
-        let key = self.generate_key();
-        let cipher = ChaCha20Poly1305::new(&key.into());
+        let key = self.key();
+
+        let payload = Payload {
+            msg: ciphertext,
+            aad,
+        };
 
-        // Decrypt the data
-        let plaintext = cipher
-            .decrypt(nonce, ciphertext)
-            .map_err(|_| "Decryption failed")?;
+        let plaintext = match mode {
+            CipherMode::ChaCha20Poly1305 => ChaCha20Poly1305::new(&(*key).into())
+                .decrypt(Nonce::from_slice(nonce_bytes), payload),
+            CipherMode::XChaCha20Poly1305 => XChaCha20Poly1305::new(&(*key).into())
+                .decrypt(XNonce::from_slice(nonce_bytes), payload),
+            CipherMode::ChaCha8Poly1305 => ChaCha8Poly1305::new(&(*key).into())
+                .decrypt(Nonce::from_slice(nonce_bytes), payload),
+            CipherMode::ChaCha12Poly1305 => ChaCha12Poly1305::new(&(*key).into())
+                .decrypt(Nonce::from_slice(nonce_bytes), payload),
+        }
+        .map_err(|_| "Decryption failed")?;
 
         Ok(plaintext)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hasher(iterations: u32, strength: f64, axis: RotationAxis) -> Spha256 {
+        let point = Point3D {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        Spha256::new(point, axis, iterations, strength)
+    }
+
+    fn z_axis() -> RotationAxis {
+        RotationAxis {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        }
+    }
+
+    #[test]
+    fn generate_key_differs_with_iterations() {
+        let a = hasher(10, 0.1, z_axis()).generate_key();
+        let b = hasher(11, 0.1, z_axis()).generate_key();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn generate_key_with_zero_iterations_still_depends_on_parameters() {
+        let point_a = Point3D {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let point_b = Point3D {
+            x: 4.0,
+            y: 5.0,
+            z: 6.0,
+        };
+
+        let a = Spha256::new(point_a, z_axis(), 0, 0.1).generate_key();
+        let b = Spha256::new(point_b, z_axis(), 0, 0.9).generate_key();
+        assert_ne!(a, b);
+
+        let empty_digest: [u8; 32] = Sha256::digest(b"").into();
+        assert_ne!(a, empty_digest);
+        assert_ne!(b, empty_digest);
+    }
+
+    #[test]
+    fn generate_key_differs_with_strength() {
+        let a = hasher(10, 0.1, z_axis()).generate_key();
+        let b = hasher(10, 0.2, z_axis()).generate_key();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn generate_key_differs_with_rotation_axis() {
+        let a = hasher(10, 0.1, z_axis()).generate_key();
+        let b = hasher(
+            10,
+            0.1,
+            RotationAxis {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        )
+        .generate_key();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn generate_key_is_deterministic() {
+        let a = hasher(10, 0.1, z_axis()).generate_key();
+        let b = hasher(10, 0.1, z_axis()).generate_key();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn generate_key_falls_back_to_z_axis_for_zero_vector() {
+        let zero_axis = RotationAxis {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let a = hasher(10, 0.1, zero_axis).generate_key();
+        let b = hasher(10, 0.1, z_axis()).generate_key();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn rotate_by_zero_angle_is_identity() {
+        let v = (1.0, 2.0, 3.0);
+        let k = (0.0, 0.0, 1.0);
+        let (sin_theta, cos_theta) = 0.0f64.sin_cos();
+        let rotated = Spha256::rotate(v, k, sin_theta, cos_theta);
+        assert!((rotated.0 - v.0).abs() < 1e-12);
+        assert!((rotated.1 - v.1).abs() < 1e-12);
+        assert!((rotated.2 - v.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotate_quarter_turn_about_z_axis() {
+        // Rotating (1, 0, 0) by 90 degrees about the Z axis should land on (0, 1, 0).
+        let v = (1.0, 0.0, 0.0);
+        let k = (0.0, 0.0, 1.0);
+        let (sin_theta, cos_theta) = (std::f64::consts::FRAC_PI_2).sin_cos();
+        let rotated = Spha256::rotate(v, k, sin_theta, cos_theta);
+        assert!((rotated.0 - 0.0).abs() < 1e-12);
+        assert!((rotated.1 - 1.0).abs() < 1e-12);
+        assert!((rotated.2 - 0.0).abs() < 1e-12);
+    }
+}
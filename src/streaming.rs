@@ -0,0 +1,253 @@
+//! The `streaming` module adds [`Spha256::encrypt_stream`]/[`Spha256::decrypt_stream`], letting
+//! callers encrypt and decrypt data that doesn't fit in memory.
+//!
+//! Both methods are built on the `aead::stream` STREAM construction: the input is split into
+//! fixed-size chunks, each encrypted with the hasher's key (the same key
+//! [`Spha256::encrypt`] uses) and a per-chunk nonce composed of a random stream prefix plus a
+//! 32-bit big-endian counter. The final chunk is sealed with the STREAM construction's distinct
+//! last-block encoding, so dropping it (or any other chunk) makes decryption fail with an error
+//! rather than silently truncating the output. As with [`Spha256::encrypt`]/[`Spha256::decrypt`], every chunk also
+//! binds the hasher's spatial parameters as Additional Authenticated Data via
+//! [`Spha256::spatial_aad`], so a stream produced with one set of parameters fails to decrypt
+//! under another.
+
+use crate::{CipherMode, Spha256};
+use chacha20poly1305::{
+    aead::{
+        generic_array::GenericArray,
+        stream::{DecryptorBE32, EncryptorBE32},
+        KeyInit, OsRng, Payload,
+    },
+    ChaCha12Poly1305, ChaCha20Poly1305, ChaCha8Poly1305, XChaCha20Poly1305,
+};
+use rand::RngCore;
+use std::io::{self, Read, Write};
+
+/// Plaintext bytes encrypted per chunk in the STREAM construction.
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Fills `buf` from `reader`, stopping early on EOF, and returns the number of bytes filled.
+fn fill_buf<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..])?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+fn stream_error(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl Spha256 {
+    /// Encrypts `reader` into `writer` in bounded-memory chunks, using the STREAM construction.
+    ///
+    /// A small self-describing header is written first: the cipher mode tag, the plaintext
+    /// chunk size, and the random nonce prefix used to derive each chunk's nonce. Every chunk is
+    /// then written as a continuation flag, a ciphertext length, and the ciphertext itself; the
+    /// final chunk's flag marks it as the STREAM construction's last block.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source of the plaintext to encrypt.
+    /// * `writer` - Destination for the header and encrypted chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from `reader`, writing to `writer`, or encrypting a chunk
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{Point3D, RotationAxis, Spha256};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::new(point, axis, 10, 0.1);
+    ///
+    /// let mut plaintext: &[u8] = b"Large payload, streamed in chunks";
+    /// let mut encrypted = Vec::new();
+    /// hasher.encrypt_stream(&mut plaintext, &mut encrypted).expect("streaming encryption failed");
+    /// ```
+    pub fn encrypt_stream<R: Read, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let key = self.key();
+        let cipher_mode = self.cipher_mode();
+        let aad = self.spatial_aad();
+        let prefix_len = cipher_mode.nonce_len() - 5;
+        let mut nonce_prefix = vec![0u8; prefix_len];
+        OsRng.fill_bytes(&mut nonce_prefix);
+
+        writer.write_all(&[cipher_mode.tag()])?;
+        writer.write_all(&(STREAM_CHUNK_SIZE as u32).to_le_bytes())?;
+        writer.write_all(&nonce_prefix)?;
+
+        macro_rules! encrypt_with {
+            ($Cipher:ty) => {{
+                let cipher = <$Cipher>::new(&(*key).into());
+                // `encrypt_last` consumes the encryptor while `encrypt_next` only borrows it, so
+                // the encryptor is kept behind an `Option` and `take()`n on the final chunk —
+                // calling a by-value method directly from one arm of a loop's `if`/`else` trips
+                // the borrow checker, since it can't see that the `last` arm always breaks.
+                let mut encryptor = Some(EncryptorBE32::from_aead(
+                    cipher,
+                    GenericArray::from_slice(&nonce_prefix),
+                ));
+                let mut buf = vec![0u8; STREAM_CHUNK_SIZE];
+                loop {
+                    let filled = fill_buf(reader, &mut buf)?;
+                    let is_last = filled < STREAM_CHUNK_SIZE;
+                    let payload = Payload {
+                        msg: &buf[..filled],
+                        aad: &aad,
+                    };
+                    let ciphertext = if is_last {
+                        encryptor
+                            .take()
+                            .expect("encryptor consumed before the last chunk")
+                            .encrypt_last(payload)
+                            .map_err(|_| stream_error("stream encryption failed"))?
+                    } else {
+                        encryptor
+                            .as_mut()
+                            .expect("encryptor consumed before the last chunk")
+                            .encrypt_next(payload)
+                            .map_err(|_| stream_error("stream encryption failed"))?
+                    };
+
+                    writer.write_all(&[if is_last { 0 } else { 1 }])?;
+                    writer.write_all(&(ciphertext.len() as u32).to_le_bytes())?;
+                    writer.write_all(&ciphertext)?;
+
+                    if is_last {
+                        break;
+                    }
+                }
+            }};
+        }
+
+        match cipher_mode {
+            CipherMode::ChaCha20Poly1305 => encrypt_with!(ChaCha20Poly1305),
+            CipherMode::XChaCha20Poly1305 => encrypt_with!(XChaCha20Poly1305),
+            CipherMode::ChaCha8Poly1305 => encrypt_with!(ChaCha8Poly1305),
+            CipherMode::ChaCha12Poly1305 => encrypt_with!(ChaCha12Poly1305),
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts a stream produced by [`encrypt_stream`](Self::encrypt_stream) from `reader` into
+    /// `writer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source of the header and encrypted chunks.
+    /// * `writer` - Destination for the decrypted plaintext.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is malformed, the cipher mode tag is unrecognized, reading
+    /// from `reader` or writing to `writer` fails, or any chunk fails to authenticate. A stream
+    /// truncated before its last-block chunk fails with an `UnexpectedEof` read error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spatial_hasher::{Point3D, RotationAxis, Spha256};
+    /// let point = Point3D { x: 1.0, y: 2.0, z: 3.0 };
+    /// let axis = RotationAxis { x: 0.0, y: 1.0, z: 0.0 };
+    /// let hasher = Spha256::new(point, axis, 10, 0.1);
+    ///
+    /// let mut plaintext: &[u8] = b"Large payload, streamed in chunks";
+    /// let mut encrypted = Vec::new();
+    /// hasher.encrypt_stream(&mut plaintext, &mut encrypted).expect("streaming encryption failed");
+    ///
+    /// let mut decrypted = Vec::new();
+    /// hasher
+    ///     .decrypt_stream(&mut encrypted.as_slice(), &mut decrypted)
+    ///     .expect("streaming decryption failed");
+    /// assert_eq!(decrypted, b"Large payload, streamed in chunks");
+    /// ```
+    pub fn decrypt_stream<R: Read, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let mut tag = [0u8; 1];
+        reader.read_exact(&mut tag)?;
+        let cipher_mode = CipherMode::from_tag(tag[0]).map_err(stream_error)?;
+
+        // The chunk size is part of the self-describing header but this side of the stream
+        // only needs the nonce prefix length that follows it to keep decrypting.
+        let mut chunk_size_bytes = [0u8; 4];
+        reader.read_exact(&mut chunk_size_bytes)?;
+
+        let prefix_len = cipher_mode.nonce_len() - 5;
+        let mut nonce_prefix = vec![0u8; prefix_len];
+        reader.read_exact(&mut nonce_prefix)?;
+
+        let key = self.key();
+        let aad = self.spatial_aad();
+
+        macro_rules! decrypt_with {
+            ($Cipher:ty) => {{
+                let cipher = <$Cipher>::new(&(*key).into());
+                // See the matching comment in `encrypt_stream`: `decrypt_last` consumes the
+                // decryptor, so it's kept behind an `Option` and `take()`n on the final chunk.
+                let mut decryptor = Some(DecryptorBE32::from_aead(
+                    cipher,
+                    GenericArray::from_slice(&nonce_prefix),
+                ));
+                loop {
+                    let mut flag = [0u8; 1];
+                    reader.read_exact(&mut flag)?;
+                    let mut len_bytes = [0u8; 4];
+                    reader.read_exact(&mut len_bytes)?;
+                    let len = u32::from_le_bytes(len_bytes) as usize;
+                    let mut ciphertext = vec![0u8; len];
+                    reader.read_exact(&mut ciphertext)?;
+
+                    let is_last = flag[0] == 0;
+                    let payload = Payload {
+                        msg: ciphertext.as_slice(),
+                        aad: &aad,
+                    };
+                    let plaintext = if is_last {
+                        decryptor
+                            .take()
+                            .expect("decryptor consumed before the last chunk")
+                            .decrypt_last(payload)
+                            .map_err(|_| stream_error("stream decryption failed"))?
+                    } else {
+                        decryptor
+                            .as_mut()
+                            .expect("decryptor consumed before the last chunk")
+                            .decrypt_next(payload)
+                            .map_err(|_| stream_error("stream decryption failed"))?
+                    };
+                    writer.write_all(&plaintext)?;
+
+                    if is_last {
+                        break;
+                    }
+                }
+            }};
+        }
+
+        match cipher_mode {
+            CipherMode::ChaCha20Poly1305 => decrypt_with!(ChaCha20Poly1305),
+            CipherMode::XChaCha20Poly1305 => decrypt_with!(XChaCha20Poly1305),
+            CipherMode::ChaCha8Poly1305 => decrypt_with!(ChaCha8Poly1305),
+            CipherMode::ChaCha12Poly1305 => decrypt_with!(ChaCha12Poly1305),
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use spatial_hasher::{Point3D, RotationAxis, Spha256};
+use spatial_hasher::{CipherMode, DecodeError, EncodingError, KeyPair, Point3D, RotationAxis, Spha256};
 
 #[test]
 fn test_encryption_decryption() {
@@ -22,3 +22,209 @@ fn test_encryption_decryption() {
 
     assert_eq!(data, &decrypted[..]);
 }
+
+#[test]
+fn test_encryption_decryption_with_cipher_modes() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let data = b"Test Data";
+
+    for cipher_mode in [
+        CipherMode::ChaCha20Poly1305,
+        CipherMode::XChaCha20Poly1305,
+        CipherMode::ChaCha8Poly1305,
+        CipherMode::ChaCha12Poly1305,
+    ] {
+        let hasher = Spha256::with_cipher_mode(point, axis, 10, 0.1, cipher_mode);
+        let encrypted = hasher.encrypt(data);
+        let decrypted = hasher.decrypt(&encrypted).expect("Decryption failed");
+        assert_eq!(data, &decrypted[..]);
+    }
+}
+
+#[test]
+fn test_decrypt_fails_on_mismatched_spatial_parameters() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let data = b"Test Data";
+
+    let hasher = Spha256::new(point, axis, 10, 0.1);
+    let encrypted = hasher.encrypt(data);
+
+    // A different iteration count changes both the derived key and the spatial AAD, so
+    // decryption fails; `test_encrypt_decrypt_with_explicit_aad` below isolates AAD binding on
+    // its own by holding the key fixed and varying only the explicit `aad` argument.
+    let other_hasher = Spha256::new(point, axis, 11, 0.1);
+    assert!(other_hasher.decrypt(&encrypted).is_err());
+}
+
+#[test]
+fn test_encrypt_decrypt_with_explicit_aad() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let hasher = Spha256::new(point, axis, 10, 0.1);
+
+    let data = b"Test Data";
+    let encrypted = hasher.encrypt_with_aad(data, b"context-1");
+
+    assert!(hasher.decrypt_with_aad(&encrypted, b"context-2").is_err());
+    let decrypted = hasher
+        .decrypt_with_aad(&encrypted, b"context-1")
+        .expect("Decryption failed");
+    assert_eq!(data, &decrypted[..]);
+}
+
+#[test]
+fn test_encrypt_decrypt_stream_roundtrip() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let hasher = Spha256::new(point, axis, 10, 0.1);
+
+    // Large enough to span multiple chunks at the streaming implementation's chunk size.
+    let data = vec![0x42u8; 200_000];
+
+    let mut encrypted = Vec::new();
+    hasher
+        .encrypt_stream(&mut data.as_slice(), &mut encrypted)
+        .expect("streaming encryption failed");
+
+    let mut decrypted = Vec::new();
+    hasher
+        .decrypt_stream(&mut encrypted.as_slice(), &mut decrypted)
+        .expect("streaming decryption failed");
+
+    assert_eq!(data, decrypted);
+}
+
+#[test]
+fn test_key_exchange_round_trip() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+
+    let alice_hasher = Spha256::from_key_exchange(&alice, &bob.public(), point, axis, 10, 0.1);
+    let bob_hasher = Spha256::from_key_exchange(&bob, &alice.public(), point, axis, 10, 0.1);
+
+    let data = b"Test Data";
+    let encrypted = alice_hasher.encrypt(data);
+    let decrypted = bob_hasher.decrypt(&encrypted).expect("Decryption failed");
+
+    assert_eq!(data, &decrypted[..]);
+}
+
+#[test]
+fn test_key_exchange_differs_from_shared_parameters_key() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+
+    let alice = KeyPair::generate();
+    let bob = KeyPair::generate();
+
+    let alice_hasher = Spha256::from_key_exchange(&alice, &bob.public(), point, axis, 10, 0.1);
+    let plain_hasher = Spha256::new(point, axis, 10, 0.1);
+
+    // Two hashers sharing the same spatial parameters but no ECDH exchange must not be
+    // able to decrypt each other's ciphertexts.
+    let encrypted = alice_hasher.encrypt(b"Test Data");
+    assert!(plain_hasher.decrypt(&encrypted).is_err());
+}
+
+#[test]
+fn test_encrypt_decrypt_hex_and_base85_round_trip() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let hasher = Spha256::new(point, axis, 10, 0.1);
+    let data = b"Test Data";
+
+    let hex = hasher.encrypt_to_hex(data);
+    let decrypted = hasher.decrypt_from_hex(&hex).expect("Decryption failed");
+    assert_eq!(data, &decrypted[..]);
+
+    let base85 = hasher.encrypt_to_base85(data);
+    let decrypted = hasher
+        .decrypt_from_base85(&base85)
+        .expect("Decryption failed");
+    assert_eq!(data, &decrypted[..]);
+}
+
+#[test]
+fn test_decrypt_from_hex_rejects_bad_input() {
+    let point = Point3D {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let axis = RotationAxis {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let hasher = Spha256::new(point, axis, 10, 0.1);
+
+    assert!(matches!(
+        hasher.decrypt_from_hex("abc"),
+        Err(DecodeError::Encoding(EncodingError::InvalidLength))
+    ));
+    assert!(matches!(
+        hasher.decrypt_from_hex("zz"),
+        Err(DecodeError::Encoding(EncodingError::InvalidCharacter('z')))
+    ));
+}